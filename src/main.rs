@@ -1,9 +1,11 @@
 use chrono::{DateTime, Utc};
 use clap::Parser;
 use ethereum_types::U256;
+use futures::stream::{self, StreamExt, TryStreamExt};
 use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /// Represents supported UniSat chain transaction categories.
 #[derive(Debug, enum_display_derive::Display, PartialEq)]
@@ -46,12 +48,16 @@ impl Display for Category {
 
 /// Transaction status.
 ///
-/// We use this to ensure the transactions we encounter have succeeded.
-/// If we ever encounter a failed transaction, we'll have to update
-/// our logic to handle those.
+/// Real wallets contain failed and pending transactions alongside
+/// successful ones, so we parse all three rather than aborting the export
+/// on the first non-success row. Non-success inscriptions are still emitted,
+/// with their state surfaced in the CSV description so tax-import tooling can
+/// filter them out.
 #[derive(Debug, PartialEq)]
 enum State {
     Success,
+    Failed,
+    Pending,
 }
 
 impl FromStr for State {
@@ -60,34 +66,126 @@ impl FromStr for State {
     fn from_str(input: &str) -> Result<State, Self::Err> {
         match input {
             "success" => Ok(State::Success),
+            "fail" | "failed" => Ok(State::Failed),
+            "pending" => Ok(State::Pending),
             _ => Err(format!("Unknown state: {}", &input)),
         }
     }
 }
 
-/// We only expect to encounter BRC20 tokens, but if we encounter
-/// others, we'll be alerted by this types failure to parse.
-#[derive(Debug, enum_display_derive::Display, PartialEq)]
+/// Inscription token standard.
+///
+/// BRC20 is by far the most common, but the same OKLink transaction model
+/// covers other inscription standards. We recognize the ones we've seen and
+/// fall back to `Other`, preserving the raw type string, rather than
+/// refusing to run on a mixed-standard wallet.
+#[derive(Debug, PartialEq)]
 enum TokenType {
     BRC20,
+    SRC20,
+    ARC20,
+    SLP,
+    ALP,
+    Other(String),
+}
+
+impl Display for TokenType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            TokenType::BRC20 => write!(f, "BRC20"),
+            TokenType::SRC20 => write!(f, "SRC20"),
+            TokenType::ARC20 => write!(f, "ARC20"),
+            TokenType::SLP => write!(f, "SLP"),
+            TokenType::ALP => write!(f, "ALP"),
+            TokenType::Other(raw) => write!(f, "{}", raw),
+        }
+    }
 }
 
 impl FromStr for TokenType {
     type Err = String;
 
     fn from_str(input: &str) -> Result<TokenType, Self::Err> {
-        match input {
-            "BRC20" => Ok(TokenType::BRC20),
-            _ => Err(format!("Unknown token type: {}", &input)),
-        }
+        Ok(match input {
+            "BRC20" => TokenType::BRC20,
+            "SRC20" => TokenType::SRC20,
+            "ARC20" => TokenType::ARC20,
+            "SLP" => TokenType::SLP,
+            "ALP" => TokenType::ALP,
+            other => TokenType::Other(other.to_string()),
+        })
+    }
+}
+
+/// Top-level command line interface.
+#[derive(Debug, clap::Parser)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// The operation to run.
+#[derive(Debug, clap::Subcommand)]
+enum Command {
+    /// Export a wallet's full inscription history to CSV and/or JSON.
+    Export(Args),
+    /// Look up a single transaction or inscription and print/export it.
+    Lookup(LookupArgs),
+}
+
+/// Output format for a wallet export.
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+enum Format {
+    Csv,
+    Json,
+    Both,
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Format::Csv => "csv",
+                Format::Json => "json",
+                Format::Both => "both",
+            }
+        )
     }
 }
 
-/// Command line arguments.
-#[derive(Debug, Default, clap::Parser)]
+/// Command line arguments for the wallet export.
+#[derive(Debug, clap::Args)]
 struct Args {
     api_key: String,
     wallet: String,
+    /// Which output format(s) to write.
+    #[arg(long, value_enum, default_value_t = Format::Csv)]
+    format: Format,
+    /// How many times to attempt each page request before giving up.
+    #[arg(long, default_value_t = 5)]
+    max_attempts: u32,
+    /// How many pages to fetch on each rebuilt `reqwest` client, to shed
+    /// stale keep-alive sockets. The first page is fetched on its own client.
+    #[arg(long, default_value_t = 50)]
+    requests_per_client: usize,
+}
+
+/// Command line arguments for the single-record lookup.
+///
+/// Exactly one of `tx_id` or `inscription_id` must be supplied; the lookup
+/// targets OKLink's per-transaction or per-inscription endpoint rather than
+/// the wallet list.
+#[derive(Debug, clap::Args)]
+struct LookupArgs {
+    api_key: String,
+    /// The transaction hash / `txId` to look up.
+    #[arg(long, required_unless_present = "inscription_id")]
+    tx_id: Option<String>,
+    /// The `inscriptionId` to look up.
+    #[arg(long, conflicts_with = "tx_id")]
+    inscription_id: Option<String>,
 }
 
 /// Represents a CSV row, formatted to CTC's schema.
@@ -103,6 +201,47 @@ struct CsvRow {
     description: String,
 }
 
+/// A serializable, normalized view of an `Inscription`.
+///
+/// The wire and domain types don't implement `Serialize` (and `amount` /
+/// `date_time` need stable string encodings), so we project into this struct
+/// before writing JSON.
+#[derive(Debug, serde::Serialize)]
+struct InscriptionJson {
+    action: String,
+    amount: String,
+    date_time: String,
+    from_address: String,
+    inscription_id: String,
+    state: String,
+    to_address: String,
+    token: String,
+    token_type: String,
+    tx_id: String,
+}
+
+impl From<&Inscription> for InscriptionJson {
+    fn from(inscription: &Inscription) -> Self {
+        InscriptionJson {
+            action: inscription.action.to_string(),
+            amount: inscription.amount.to_string(),
+            date_time: inscription.date_time.to_rfc3339(),
+            from_address: inscription.from_address.clone(),
+            inscription_id: inscription.inscription_id.clone(),
+            state: match inscription.state {
+                State::Success => "success",
+                State::Failed => "failed",
+                State::Pending => "pending",
+            }
+            .to_string(),
+            to_address: inscription.to_address.clone(),
+            token: inscription.token.clone(),
+            token_type: inscription.token_type.to_string(),
+            tx_id: inscription.tx_id.clone(),
+        }
+    }
+}
+
 /// Represents the relevant data for an inscription transfer.
 #[derive(Debug)]
 struct Inscription {
@@ -111,8 +250,6 @@ struct Inscription {
     date_time: DateTime<Utc>,
     from_address: String,
     inscription_id: String,
-    // This value is being used implicitly during deserialization to ensure the txn was successful
-    #[allow(dead_code)]
     state: State,
     to_address: String,
     token: String,
@@ -164,51 +301,269 @@ struct ResponseRaw {
     data: Vec<PaginationRaw>,
 }
 
+/// OKLink per-transaction/inscription detail response. Unlike the wallet list,
+/// the detail endpoints return the matching record(s) directly under `data`
+/// without the pagination envelope, so we decode straight into `InscriptionRaw`.
+#[derive(Clone, Debug, serde::Deserialize)]
+struct DetailResponseRaw {
+    data: Vec<InscriptionRaw>,
+}
+
+/// Classifies a page fetch failure so the retry wrapper knows whether the
+/// request is worth another attempt and, for rate limits, how long to wait.
+#[derive(Debug)]
+enum FetchError {
+    /// A transient failure (connection reset, HTTP 429/5xx, JSON decode);
+    /// retrying after a backoff may succeed. `retry_after` carries any
+    /// server-suggested delay from a `Retry-After` header.
+    Transient {
+        source: Box<dyn Error>,
+        retry_after: Option<Duration>,
+    },
+    /// A failure retrying will not fix (e.g. an unexpected row we can't parse).
+    Fatal(Box<dyn Error>),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            FetchError::Transient { source, .. } => write!(f, "{}", source),
+            FetchError::Fatal(source) => write!(f, "{}", source),
+        }
+    }
+}
+
+impl Error for FetchError {}
+
 /// Our program's entrypoint.
-fn main() -> Result<(), Box<dyn Error>> {
-    let args = Args::parse();
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    match Cli::parse().command {
+        Command::Export(args) => run_export(args).await,
+        Command::Lookup(args) => run_lookup(args).await,
+    }
+}
+
+/// Export a wallet's full inscription history in the format(s) chosen by
+/// `args.format`.
+async fn run_export(args: Args) -> Result<(), Box<dyn Error>> {
     println!(
         "Fetching inscriptions for wallet {} using API Key {}",
         args.wallet, args.api_key
     );
-    let client = reqwest::blocking::Client::new();
-    let mut inscriptions: Vec<Inscription> = vec![];
-    fetch_pages(args, client, &mut inscriptions, 0)?;
+    let inscriptions = fetch_pages(&args).await?;
     println!("{:#?}", inscriptions);
     println!("Total inscritions: {}", inscriptions.len());
-    write_csv(inscriptions)?;
+    match args.format {
+        Format::Csv => write_csv(inscriptions)?,
+        Format::Json => write_json(&inscriptions)?,
+        Format::Both => {
+            write_json(&inscriptions)?;
+            write_csv(inscriptions)?;
+        }
+    }
+    Ok(())
+}
+
+/// Look up a single transaction or inscription, printing the pretty debug
+/// form and writing the same CTC CSV row as the wallet export.
+async fn run_lookup(args: LookupArgs) -> Result<(), Box<dyn Error>> {
+    let client = reqwest::Client::new();
+    let inscription = fetch_single(&args, &client).await?;
+    println!("{:#?}", inscription);
+    write_csv(vec![inscription])?;
     Ok(())
 }
 
+/// Fetch a single inscription by `txId` or `inscriptionId` from OKLink's
+/// per-transaction or per-inscription endpoint, reusing the
+/// `InscriptionRaw` -> `Inscription` conversion path.
+async fn fetch_single(
+    args: &LookupArgs,
+    client: &reqwest::Client,
+) -> Result<Inscription, Box<dyn Error>> {
+    // Target OKLink's per-transaction or per-inscription endpoint directly,
+    // keyed on the hash or inscription id, rather than the address-scoped wallet list: the latter
+    // needs an `address` and doesn't honor a `txId`/`inscriptionId` filter.
+    let url = match (&args.tx_id, &args.inscription_id) {
+        (Some(tx_id), _) => format!(
+            "https://www.oklink.com/api/v5/explorer/btc/transaction/{}",
+            tx_id
+        ),
+        (_, Some(inscription_id)) => format!(
+            "https://www.oklink.com/api/v5/explorer/btc/inscription/{}",
+            inscription_id
+        ),
+        (None, None) => return Err("must supply --tx-id or --inscription-id".into()),
+    };
+    let res = client
+        .get(url)
+        .header("Ok-Access-Key", &args.api_key)
+        .header("Content-Type", "application/json")
+        .send()
+        .await?;
+    let body = res.text().await?;
+    process_detail_response(&body)
+}
+
+/// Decode a detail-endpoint response body and convert the first record via the
+/// shared `InscriptionRaw` -> `Inscription` path.
+fn process_detail_response(body: &str) -> Result<Inscription, Box<dyn Error>> {
+    let raw: DetailResponseRaw = serde_json::from_str(body)?;
+    match raw.data.first() {
+        Some(inscription) => process_inscription(inscription),
+        None => Err("No inscription found".into()),
+    }
+}
+
+/// How many page requests may be in flight at once. Kept well below the
+/// client-refresh interval so we don't manufacture 429s on a rate-limited
+/// API and fight the retry backoff.
+const MAX_CONCURRENT_REQUESTS: usize = 8;
+
 /// Fetch all the inscriptions for a given wallet address.
 ///
-/// Will fetch one page at a time, recursively calling itself until all
-/// pages have been fetched.
-fn fetch_pages(
-    args: Args,
-    client: reqwest::blocking::Client,
-    inscriptions: &mut Vec<Inscription>,
+/// Issues the first request to learn the total page count, then fans out
+/// the remaining pages concurrently rather than waiting on each one in
+/// turn. In-flight requests are capped via `buffer_unordered` so a large
+/// wallet doesn't burst dozens of requests at once. The pages are fetched
+/// in chunks, rebuilding the `reqwest` client before each chunk so stale
+/// keep-alive sockets don't cause repeated mid-run failures, and sorted
+/// back into page order before being returned.
+async fn fetch_pages(args: &Args) -> Result<Vec<Inscription>, Box<dyn Error>> {
+    let mut client = reqwest::Client::new();
+
+    // The first request tells us how many pages there are.
+    let first = fetch_page_with_retry(args, &client, 1).await?;
+    let total_pages = first.total_pages;
+    let mut pages = vec![first];
+
+    let remaining: Vec<i32> = (2..=total_pages).collect();
+    let chunk_len = args.requests_per_client.max(1);
+    for chunk in remaining.chunks(chunk_len) {
+        client = reqwest::Client::new();
+        let batch: Vec<Pagination> = stream::iter(chunk.iter().copied())
+            .map(|page| fetch_page_with_retry(args, &client, page))
+            .buffer_unordered(MAX_CONCURRENT_REQUESTS)
+            .try_collect()
+            .await?;
+        pages.extend(batch);
+    }
+
+    // `buffer_unordered` yields pages as they complete, so restore page
+    // order before flattening the inscriptions back together.
+    pages.sort_by_key(|page| page.page);
+    let mut inscriptions: Vec<Inscription> = vec![];
+    for page in pages {
+        inscriptions.extend(page.inscriptions);
+    }
+    Ok(inscriptions)
+}
+
+/// Fetch a single page, retrying transient failures with exponential
+/// backoff plus jitter up to `args.max_attempts` times. Honors a server's
+/// `Retry-After` hint on 429 responses.
+async fn fetch_page_with_retry(
+    args: &Args,
+    client: &reqwest::Client,
     page: i32,
-) -> Result<&mut Vec<Inscription>, Box<dyn Error>> {
+) -> Result<Pagination, Box<dyn Error>> {
+    let mut attempt = 1;
+    loop {
+        match fetch_page(args, client, page).await {
+            Ok(pagination) => return Ok(pagination),
+            Err(FetchError::Fatal(source)) => return Err(source),
+            Err(FetchError::Transient {
+                source,
+                retry_after,
+            }) => {
+                if attempt >= args.max_attempts {
+                    return Err(source);
+                }
+                let delay = retry_after.unwrap_or_else(|| backoff_delay(attempt));
+                println!(
+                    "page {} attempt {} failed ({}); retrying in {:?}",
+                    page, attempt, source, delay
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// Fetch and process a single page of the wallet's transaction list.
+async fn fetch_page(
+    args: &Args,
+    client: &reqwest::Client,
+    page: i32,
+) -> Result<Pagination, FetchError> {
     println!("fetching page {}", page);
     let res = client
-        .get(format!("https://www.oklink.com/api/v5/explorer/btc/transaction-list?&page={}&limit=50&address={}", page + 1, &args.wallet))
+        .get(format!("https://www.oklink.com/api/v5/explorer/btc/transaction-list?&page={}&limit=50&address={}", page, &args.wallet))
         .header("Ok-Access-Key", &args.api_key)
         .header("Content-Type", "application/json")
-        .send()?;
-    let body = res.text()?;
-    let raw: ResponseRaw = serde_json::from_str(&body)?;
-    let pagination = process_response(&raw)?;
-    inscriptions.extend(pagination.inscriptions);
+        .send()
+        .await
+        .map_err(|e| FetchError::Transient {
+            source: Box::new(e),
+            retry_after: None,
+        })?;
+    let status = res.status();
+    if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        let retry_after = parse_retry_after(&res);
+        return Err(FetchError::Transient {
+            source: format!("HTTP {}", status).into(),
+            retry_after,
+        });
+    }
+    if status.is_server_error() {
+        return Err(FetchError::Transient {
+            source: format!("HTTP {}", status).into(),
+            retry_after: None,
+        });
+    }
+    if !status.is_success() {
+        return Err(FetchError::Fatal(format!("HTTP {}", status).into()));
+    }
+    let body = res.text().await.map_err(|e| FetchError::Transient {
+        source: Box::new(e),
+        retry_after: None,
+    })?;
+    let raw: ResponseRaw = serde_json::from_str(&body).map_err(|e| FetchError::Transient {
+        source: Box::new(e),
+        retry_after: None,
+    })?;
+    let pagination = process_response(&raw).map_err(FetchError::Fatal)?;
     println!(
         "fetched page {} out of {}",
         pagination.page, pagination.total_pages
     );
-    if pagination.page == pagination.total_pages {
-        Ok(inscriptions)
-    } else {
-        fetch_pages(args, client, inscriptions, page + 1)
-    }
+    Ok(pagination)
+}
+
+/// Compute the backoff delay for a given attempt: exponential growth on a
+/// 500ms base, plus up to one base of jitter to avoid a thundering herd.
+fn backoff_delay(attempt: u32) -> Duration {
+    let base_ms = 500u64;
+    let exp = base_ms.saturating_mul(1u64 << (attempt - 1).min(6));
+    let jitter = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| u64::from(d.subsec_nanos()) % base_ms)
+        .unwrap_or(0);
+    Duration::from_millis(exp + jitter)
+}
+
+/// Parse a `Retry-After` header expressed as a whole number of seconds.
+fn parse_retry_after(res: &reqwest::Response) -> Option<Duration> {
+    res.headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
 }
 
 /// Convert primitive OK Link data to more useful `Inscription`.
@@ -268,14 +623,24 @@ fn to_csv_row(inscription: Inscription) -> CsvRow {
     CsvRow {
         timestamp: format!("{}", inscription.date_time.format("%Y/%m/%d %H:%M:%S")),
         category: format!("{}", category),
+        // CTC keys trades off the traded asset, so `base_currency` stays the
+        // token ticker; the concrete standard is surfaced in `description`.
         base_currency: inscription.token.clone(),
         base_amount: format!("{}", inscription.amount),
         from: inscription.from_address.clone(),
         to: inscription.to_address.clone(),
         hash: inscription.tx_id.clone(),
         description: format!(
-            "{} {} with inscription_id {}",
-            inscription.token_type, inscription.action, inscription.inscription_id
+            "{} {} with inscription_id {}{}",
+            inscription.token_type,
+            inscription.action,
+            inscription.inscription_id,
+            // Flag non-success rows so they can be filtered on import.
+            match inscription.state {
+                State::Success => String::new(),
+                State::Failed => String::from(" (failed transaction)"),
+                State::Pending => String::from(" (pending transaction)"),
+            }
         ),
     }
 }
@@ -331,6 +696,20 @@ fn write_csv(inscriptions: Vec<Inscription>) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+/// Given a `Vec<Inscription>` write them all to a JSON array, so downstream
+/// tools can consume the normalized data without re-parsing the CSV.
+fn write_json(inscriptions: &[Inscription]) -> Result<(), Box<dyn Error>> {
+    std::fs::create_dir_all("json")?;
+    let now = chrono::offset::Local::now();
+    let filename = format!("json/{}.json", now.format("%Y-%m-%d %H-%M-%S"));
+    println!("Writing {}", filename);
+    let views: Vec<InscriptionJson> = inscriptions.iter().map(InscriptionJson::from).collect();
+    let file = std::fs::File::create(&filename)?;
+    serde_json::to_writer_pretty(file, &views)?;
+    println!("Successfully wrote {}", filename);
+    Ok(())
+}
+
 #[test]
 fn test_to_csv_row() -> Result<(), Box<dyn Error>> {
     let inscription = Inscription {
@@ -367,3 +746,118 @@ fn test_unix_to_datetime() -> Result<(), Box<dyn Error>> {
     assert_eq!(actual, expected);
     Ok(())
 }
+
+#[test]
+fn test_state_from_str() -> Result<(), Box<dyn Error>> {
+    assert_eq!(State::from_str("success")?, State::Success);
+    assert_eq!(State::from_str("fail")?, State::Failed);
+    assert_eq!(State::from_str("failed")?, State::Failed);
+    assert_eq!(State::from_str("pending")?, State::Pending);
+    assert!(State::from_str("bogus").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_to_csv_row_state_suffix() -> Result<(), Box<dyn Error>> {
+    let base = |state| Inscription {
+        action: Action::Transfer,
+        amount: U256::zero(),
+        date_time: Utc::now(),
+        from_address: String::from("from"),
+        inscription_id: String::from("id"),
+        state,
+        to_address: String::from("to"),
+        token: String::from("sats"),
+        token_type: TokenType::BRC20,
+        tx_id: String::from("hash"),
+    };
+    assert!(to_csv_row(base(State::Success))
+        .description
+        .ends_with("inscription_id id"));
+    assert!(to_csv_row(base(State::Failed))
+        .description
+        .ends_with(" (failed transaction)"));
+    assert!(to_csv_row(base(State::Pending))
+        .description
+        .ends_with(" (pending transaction)"));
+    Ok(())
+}
+
+#[test]
+fn test_token_type_round_trip() -> Result<(), Box<dyn Error>> {
+    for standard in ["BRC20", "SRC20", "ARC20", "SLP", "ALP"] {
+        let parsed = TokenType::from_str(standard)?;
+        assert_eq!(parsed.to_string(), standard);
+    }
+    // Unknown standards fall back to `Other`, preserving the raw string.
+    let other = TokenType::from_str("DRC20")?;
+    assert_eq!(other, TokenType::Other(String::from("DRC20")));
+    assert_eq!(other.to_string(), "DRC20");
+    Ok(())
+}
+
+#[test]
+fn test_inscription_json_from() -> Result<(), Box<dyn Error>> {
+    let inscription = Inscription {
+        action: Action::Mint,
+        amount: U256::from_dec_str("1000").map_err(|e| format!("{:?}", e))?,
+        date_time: DateTime::parse_from_rfc3339("2023-07-07T01:23:45Z")?.with_timezone(&Utc),
+        from_address: String::from("from"),
+        inscription_id: String::from("inscription_id"),
+        state: State::Failed,
+        to_address: String::from("to"),
+        token: String::from("sats"),
+        token_type: TokenType::Other(String::from("DRC20")),
+        tx_id: String::from("hash"),
+    };
+    let json = InscriptionJson::from(&inscription);
+    assert_eq!(json.action, "Mint");
+    assert_eq!(json.amount, "1000");
+    assert_eq!(json.date_time, "2023-07-07T01:23:45+00:00");
+    assert_eq!(json.state, "failed");
+    assert_eq!(json.token_type, "DRC20");
+    assert_eq!(json.tx_id, "hash");
+    Ok(())
+}
+
+#[test]
+fn test_backoff_delay_grows_exponentially() {
+    // Each attempt's base doubles off 500ms; jitter adds under 500ms on top,
+    // so the delay stays within [base, base + 500ms) for that attempt.
+    for (attempt, base_ms) in [(1u32, 500u64), (2, 1000), (3, 2000)] {
+        let delay = backoff_delay(attempt).as_millis() as u64;
+        assert!(delay >= base_ms, "attempt {} below base", attempt);
+        assert!(delay < base_ms + 500, "attempt {} above base+jitter", attempt);
+    }
+    // The exponent is capped at a shift of 6 (500ms * 64 = 32000ms base), so
+    // even a very high attempt count can't grow the delay without bound.
+    let capped = backoff_delay(100).as_millis() as u64;
+    assert!((32000..32500).contains(&capped));
+}
+
+#[test]
+fn test_process_detail_response() -> Result<(), Box<dyn Error>> {
+    let body = r#"{
+        "data": [
+            {
+                "actionType": "transfer",
+                "amount": "1000",
+                "fromAddress": "from",
+                "inscriptionId": "inscription_id",
+                "state": "success",
+                "time": "1685092041000",
+                "toAddress": "to",
+                "token": "sats",
+                "tokenType": "BRC20",
+                "txId": "hash"
+            }
+        ]
+    }"#;
+    let inscription = process_detail_response(body)?;
+    assert_eq!(inscription.tx_id, "hash");
+    assert_eq!(inscription.token, "sats");
+    assert_eq!(inscription.action, Action::Transfer);
+    assert_eq!(inscription.state, State::Success);
+    assert_eq!(inscription.token_type, TokenType::BRC20);
+    Ok(())
+}